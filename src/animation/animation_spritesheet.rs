@@ -0,0 +1,115 @@
+//! System driving spritesheet/sprite-sequence playback for [`AnimationPlayer2D`].
+
+use bevy::prelude::{Assets, Entity, EventWriter, Handle, Image, Query, Res, TextureAtlasSprite, Time};
+
+use crate::asset::{AnimationClip2D, Keyframes2D};
+
+use super::{AnimationEvent2D, AnimationFinished2D, AnimationPlayer2D};
+
+/// Index of the keyframe whose timestamp bracket contains `t`, i.e. the last keyframe whose
+/// timestamp is `<= t`.
+pub(crate) fn keyframe_index_at(timestamps: &[f32], t: f32) -> usize {
+    match timestamps.binary_search_by(|stamp| stamp.partial_cmp(&t).unwrap()) {
+        Ok(index) => index,
+        Err(0) => 0,
+        Err(index) => index - 1,
+    }
+}
+
+/// Index of the keyframe that should be displayed for `clip` at `seek_time`, after remapping
+/// normalized progress through the clip's [`Easing`](crate::asset::Easing) curve. Shared by the
+/// main playback system and [`AnimationTransitions2D`](super::AnimationTransitions2D) crossfades
+/// so both pick frames the same way.
+pub(crate) fn eased_keyframe_index(clip: &AnimationClip2D, seek_time: f32) -> usize {
+    let duration = clip.duration();
+    let t = if duration > 0.0 { seek_time / duration } else { 0.0 };
+    let eased_time = clip.easing().remap(t) * duration;
+    keyframe_index_at(clip.keyframe_timestamps(), eased_time)
+}
+
+/// Indices of the keyframes crossed while moving from `prev_index` to `new_index` within a
+/// single monotonic segment (see [`PlayingAnimation2D::update`](super::PlayingAnimation2D)),
+/// in the order they were crossed.
+fn keyframes_crossed_in_segment(prev_index: usize, new_index: usize) -> Vec<usize> {
+    match new_index.cmp(&prev_index) {
+        std::cmp::Ordering::Greater => ((prev_index + 1)..=new_index).collect(),
+        std::cmp::Ordering::Less => (new_index..prev_index).rev().collect(),
+        std::cmp::Ordering::Equal => Vec::new(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn animation_player_spritesheet(
+    time: Res<Time>,
+    clips: Res<Assets<AnimationClip2D>>,
+    mut animation_events: EventWriter<AnimationEvent2D>,
+    mut finished_events: EventWriter<AnimationFinished2D>,
+    mut players: Query<(
+        Entity,
+        &mut AnimationPlayer2D,
+        Option<&mut TextureAtlasSprite>,
+        Option<&mut Handle<Image>>,
+    )>,
+) {
+    for (entity, mut player, atlas_sprite, image_handle) in &mut players {
+        if player.is_paused() {
+            continue;
+        }
+
+        let Some(clip) = clips.get(player.animation_clip()) else {
+            continue;
+        };
+
+        let duration = clip.duration();
+        let timestamps = clip.keyframe_timestamps();
+        let frame_count = timestamps.len();
+
+        let segments = player.animation.update(time.delta_seconds(), duration);
+
+        if duration > 0.0 && !clip.markers().is_empty() && frame_count > 0 {
+            // Walk each monotonic segment individually rather than comparing the seek time
+            // before and after the whole call: a `PingPong` direction can reflect off a bound
+            // mid-frame, and deriving direction from a single pre-update `forward` snapshot
+            // would misattribute (skip or double-fire) markers crossed during that bounce.
+            for (segment_start, segment_end) in segments {
+                let prev_index = keyframe_index_at(timestamps, segment_start);
+                let new_index = keyframe_index_at(timestamps, segment_end);
+                for index in keyframes_crossed_in_segment(prev_index, new_index) {
+                    if let Some(marker) = clip.markers().get(&index) {
+                        animation_events.send(AnimationEvent2D {
+                            entity,
+                            clip: player.animation_clip().clone(),
+                            marker: marker.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if player.is_finished() && !player.animation.finished_event_sent {
+            player.animation.finished_event_sent = true;
+            finished_events.send(AnimationFinished2D {
+                entity,
+                clip: player.animation_clip().clone(),
+            });
+        }
+
+        let keyframe_index = eased_keyframe_index(clip, player.animation.seek_time);
+
+        match clip.keyframes() {
+            Keyframes2D::SpriteSheet(_, indices) => {
+                if let (Some(mut sprite), Some(&index)) = (atlas_sprite, indices.get(keyframe_index))
+                {
+                    sprite.index = index;
+                }
+            }
+            Keyframes2D::Sprite(handles) => {
+                if let (Some(mut handle), Some(target)) =
+                    (image_handle, handles.get(keyframe_index))
+                {
+                    *handle = target.clone();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! System driving [`AnimationTransitions2D`] crossfades and queued animations.
+
+use bevy::prelude::{Assets, Handle, Image, Mut, Query, Res, Sprite, TextureAtlasSprite, Time};
+
+use crate::asset::{AnimationClip2D, Keyframes2D};
+
+use super::animation_spritesheet::eased_keyframe_index;
+use super::{AnimationPlayer2D, AnimationTransitions2D};
+
+fn set_alpha(sprite: &mut Option<Mut<Sprite>>, atlas_sprite: &mut Option<Mut<TextureAtlasSprite>>, alpha: f32) {
+    if let Some(sprite) = sprite {
+        sprite.color = sprite.color.with_a(alpha);
+    }
+    if let Some(sprite) = atlas_sprite {
+        sprite.color = sprite.color.with_a(alpha);
+    }
+}
+
+/// Alpha to apply to the entity's sprite for a crossfade at the given `weight` of the incoming
+/// animation: fades the outgoing clip out over the first half of the transition, then fades the
+/// incoming clip in over the second half. The sprite is fully opaque at both ends of the
+/// transition and fully transparent at `weight == 0.5`, where the dominant clip (selected at
+/// `weight < 0.5` vs. `>= 0.5`) swaps.
+fn crossfade_alpha(weight: f32) -> f32 {
+    let alpha = if weight < 0.5 {
+        1.0 - weight * 2.0
+    } else {
+        weight * 2.0 - 1.0
+    };
+    alpha.clamp(0.0, 1.0)
+}
+
+pub(crate) fn animation_transitions_2d(
+    time: Res<Time>,
+    clips: Res<Assets<AnimationClip2D>>,
+    mut query: Query<(
+        &mut AnimationPlayer2D,
+        &mut AnimationTransitions2D,
+        Option<&mut Sprite>,
+        Option<&mut TextureAtlasSprite>,
+        Option<&mut Handle<Image>>,
+    )>,
+) {
+    for (mut player, mut transitions, mut sprite, mut atlas_sprite, mut image_handle) in &mut query
+    {
+        if player.is_paused() {
+            continue;
+        }
+
+        if let Some(queued) = transitions.queued.clone() {
+            if player.is_finished() {
+                player.start(queued);
+                transitions.queued = None;
+            }
+        }
+
+        let Some(outgoing) = transitions.outgoing.as_mut() else {
+            continue;
+        };
+
+        let delta = time.delta_seconds();
+        if let Some(clip) = clips.get(&outgoing.animation_clip) {
+            outgoing.update(delta, clip.duration());
+        }
+        transitions.weight = (transitions.weight + transitions.weight_decline_per_sec * delta).min(1.0);
+
+        let (dominant_handle, dominant_seek_time) = if transitions.weight < 0.5 {
+            let outgoing = transitions.outgoing.as_ref().unwrap();
+            (outgoing.animation_clip.clone(), outgoing.seek_time)
+        } else {
+            (player.animation_clip().clone(), player.seek_time())
+        };
+
+        if let Some(clip) = clips.get(&dominant_handle) {
+            let index = eased_keyframe_index(clip, dominant_seek_time);
+
+            match clip.keyframes() {
+                Keyframes2D::SpriteSheet(_, indices) => {
+                    if let (Some(sprite), Some(&idx)) = (atlas_sprite.as_mut(), indices.get(index))
+                    {
+                        sprite.index = idx;
+                    }
+                }
+                Keyframes2D::Sprite(handles) => {
+                    if let (Some(handle), Some(target)) =
+                        (image_handle.as_mut(), handles.get(index))
+                    {
+                        **handle = target.clone();
+                    }
+                }
+            }
+        }
+
+        set_alpha(&mut sprite, &mut atlas_sprite, crossfade_alpha(transitions.weight));
+
+        if transitions.weight >= 1.0 {
+            transitions.outgoing = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossfade_starts_fully_outgoing_and_ends_fully_incoming() {
+        assert_eq!(crossfade_alpha(0.0), 1.0);
+        assert_eq!(crossfade_alpha(1.0), 1.0);
+    }
+
+    #[test]
+    fn crossfade_dips_at_the_midpoint() {
+        assert!((crossfade_alpha(0.5) - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn crossfade_alpha_is_monotonic_on_each_half() {
+        assert!(crossfade_alpha(0.1) > crossfade_alpha(0.4));
+        assert!(crossfade_alpha(0.6) < crossfade_alpha(0.9));
+    }
+}
@@ -2,15 +2,19 @@
 //!
 
 mod animation_spritesheet;
+mod animation_transitions;
+
+use std::time::Duration;
 
 use crate::prelude::AnimationClip2D;
 use bevy::{
     animation::RepeatAnimation,
-    prelude::{App, Component, Handle, Plugin, ReflectComponent, Update},
+    prelude::{App, Assets, Component, Entity, Event, Handle, Plugin, ReflectComponent, Update},
     reflect::Reflect,
 };
 
 use self::animation_spritesheet::animation_player_spritesheet;
+use self::animation_transitions::animation_transitions_2d;
 
 /// Adds support for spritesheet animation playing.
 pub struct AnimationPlayer2DPlugin;
@@ -18,33 +22,82 @@ pub struct AnimationPlayer2DPlugin;
 impl Plugin for AnimationPlayer2DPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<AnimationPlayer2D>()
-            .add_systems(Update, animation_player_spritesheet);
+            .register_type::<AnimationDirection>()
+            .register_type::<AnimationTransitions2D>()
+            .add_event::<AnimationEvent2D>()
+            .add_event::<AnimationFinished2D>()
+            .add_systems(
+                Update,
+                (animation_player_spritesheet, animation_transitions_2d).chain(),
+            );
     }
 }
 
+/// Event emitted when a playing animation's `seek_time` crosses a keyframe that has a marker
+/// attached to it in the clip's manifest.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationEvent2D {
+    /// Entity the animation is playing on.
+    pub entity: Entity,
+    /// Handle of the clip that triggered the event.
+    pub clip: Handle<AnimationClip2D>,
+    /// Name of the marker attached to the crossed keyframe.
+    pub marker: String,
+}
+
+/// Event emitted the first time a playing animation's [`AnimationPlayer2D::is_finished`] becomes
+/// `true`.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationFinished2D {
+    /// Entity the animation was playing on.
+    pub entity: Entity,
+    /// Handle of the clip that finished.
+    pub clip: Handle<AnimationClip2D>,
+}
+
+/// Direction in which a [`PlayingAnimation2D`] advances its `seek_time`.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationDirection {
+    /// Advance `seek_time` from `0.0` towards the clip's duration.
+    #[default]
+    Forwards,
+    /// Advance `seek_time` from the clip's duration towards `0.0`.
+    Backwards,
+    /// Alternate between [`Forwards`](AnimationDirection::Forwards) and
+    /// [`Backwards`](AnimationDirection::Backwards) each time the clip reaches an end,
+    /// counting one completion per full out-and-back cycle.
+    PingPong,
+}
+
 #[derive(Reflect)]
 struct PlayingAnimation2D {
     repeat: RepeatAnimation,
-    reverse: bool,
+    direction: AnimationDirection,
+    /// Current travel direction for [`AnimationDirection::PingPong`]. `true` means forwards.
+    forward: bool,
     clip_finished: bool,
     speed: f32,
     elapsed: f32,
     seek_time: f32,
     animation_clip: Handle<AnimationClip2D>,
     completions: u32,
+    /// Whether an [`AnimationFinished2D`] event has already been emitted for the current play-through.
+    finished_event_sent: bool,
 }
 
 impl Default for PlayingAnimation2D {
     fn default() -> Self {
         Self {
             repeat: Default::default(),
-            reverse: false,
+            direction: Default::default(),
+            forward: true,
             clip_finished: false,
             speed: 1.0,
             elapsed: 0.0,
             seek_time: 0.0,
             animation_clip: Default::default(),
             completions: 0,
+            finished_event_sent: false,
         }
     }
 }
@@ -63,26 +116,98 @@ impl PlayingAnimation2D {
     }
 
     /// Update the animation given the delta time and the duration of the clip being played.
+    ///
+    /// Returns the raw (pre-easing) `seek_time` segments traversed this call, each monotonic in
+    /// one direction. There is more than one segment when playback wraps around on repeat, or
+    /// bounces off an end during [`AnimationDirection::PingPong`] — callers that need to know
+    /// exactly which keyframes were passed through (e.g. to fire markers) should walk each
+    /// segment individually rather than assuming a single monotonic range between the seek time
+    /// before and after this call.
     #[inline]
-    fn update(&mut self, delta: f32, clip_duration: f32) {
+    fn update(&mut self, delta: f32, clip_duration: f32) -> Vec<(f32, f32)> {
         if self.is_finished() {
-            return;
+            return Vec::new();
+        }
+
+        if clip_duration <= 0.0 {
+            self.seek_time = 0.0;
+            return Vec::new();
         }
 
-        let direction_multiplier = if self.reverse { -1.0 } else { 1.0 };
         self.elapsed += delta;
-        self.seek_time += delta * self.speed * direction_multiplier;
 
-        if self.seek_time >= clip_duration {
-            self.seek_time %= clip_duration;
-        } else if self.seek_time < 0.0 {
-            self.seek_time += clip_duration;
+        let mut segments = Vec::new();
+        let mut segment_start = self.seek_time;
+
+        match self.direction {
+            AnimationDirection::Forwards | AnimationDirection::Backwards => {
+                let direction_multiplier = if self.direction == AnimationDirection::Backwards {
+                    -1.0
+                } else {
+                    1.0
+                };
+                self.seek_time += delta * self.speed * direction_multiplier;
+
+                if self.seek_time >= clip_duration {
+                    segments.push((segment_start, clip_duration));
+                    self.seek_time %= clip_duration;
+                    segment_start = 0.0;
+                } else if self.seek_time < 0.0 {
+                    segments.push((segment_start, 0.0));
+                    self.seek_time += clip_duration;
+                    segment_start = clip_duration;
+                }
+
+                let reverse = self.direction == AnimationDirection::Backwards;
+                if (reverse && self.seek_time <= 0.0) || (!reverse && self.seek_time >= clip_duration) {
+                    self.completions += 1;
+                    self.seek_time = if reverse { clip_duration } else { 0.0 };
+                }
+                segments.push((segment_start, self.seek_time));
+            }
+            AnimationDirection::PingPong => {
+                let direction_multiplier = if self.forward { 1.0 } else { -1.0 };
+                self.seek_time += delta * self.speed * direction_multiplier;
+
+                // Reflect off each end in a loop rather than a single step, so an oversized
+                // `delta` (a frame hitch) that overshoots by more than one full bounce still
+                // converges back into `[0.0, clip_duration]` instead of leaving `seek_time`
+                // out of range for the next update. Bounded to avoid a pathologically short
+                // clip combined with a huge `delta` (e.g. resuming from a backgrounded app)
+                // spending an unbounded number of iterations on a single frame.
+                //
+                // Which branch fires is decided by which bound `seek_time` actually crossed,
+                // not by the `forward` flag: with a negative `speed`, `direction_multiplier`
+                // (derived from `forward`) no longer matches the sign of the actual motion, so
+                // checking `forward`/`!forward` against the crossed bound would never match and
+                // freeze the animation at whichever bound it first reached.
+                const MAX_BOUNCES: u32 = 64;
+                for _ in 0..MAX_BOUNCES {
+                    if self.seek_time >= clip_duration {
+                        segments.push((segment_start, clip_duration));
+                        self.seek_time = clip_duration - (self.seek_time - clip_duration);
+                        self.forward = !self.forward;
+                        segment_start = clip_duration;
+                    } else if self.seek_time < 0.0 {
+                        segments.push((segment_start, 0.0));
+                        self.seek_time = -self.seek_time;
+                        self.forward = !self.forward;
+                        self.completions += 1;
+                        segment_start = 0.0;
+
+                        if self.is_finished() {
+                            break;
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                self.seek_time = self.seek_time.clamp(0.0, clip_duration);
+                segments.push((segment_start, self.seek_time));
+            }
         }
 
-        if (self.reverse && self.seek_time <= 0.0) || (!self.reverse && self.seek_time >= clip_duration) {
-            self.completions += 1;
-            self.seek_time = if self.reverse { clip_duration } else { 0.0 };
-        }
+        segments
     }
 
     /// Reset back to the initial state as if no time has elapsed.
@@ -90,6 +215,8 @@ impl PlayingAnimation2D {
         self.completions = 0;
         self.elapsed = 0.0;
         self.seek_time = 0.0;
+        self.forward = true;
+        self.finished_event_sent = false;
     }
 }
 
@@ -140,20 +267,35 @@ impl AnimationPlayer2D {
     }
 
     /// Play animation in reverse.
+    ///
+    /// This is a thin wrapper over [`Self::set_direction`] for backward compatibility.
     pub fn reverse(&mut self) -> &mut Self {
-        self.animation.reverse = true;
+        self.animation.direction = AnimationDirection::Backwards;
         self
     }
 
     /// Stop playing animation in reverse.
+    ///
+    /// This is a thin wrapper over [`Self::set_direction`] for backward compatibility.
     pub fn stop_reverse(&mut self) -> &mut Self {
-        self.animation.reverse = false;
+        self.animation.direction = AnimationDirection::Forwards;
         self
     }
 
     /// Is the animation playing in reverse
     pub fn is_reverse(&self) -> bool {
-        self.animation.reverse
+        self.animation.direction == AnimationDirection::Backwards
+    }
+
+    /// Set the direction in which the animation advances.
+    pub fn set_direction(&mut self, direction: AnimationDirection) -> &mut Self {
+        self.animation.direction = direction;
+        self
+    }
+
+    /// Direction in which the animation advances.
+    pub fn direction(&self) -> AnimationDirection {
+        self.animation.direction
     }
 
     /// Sets repeat to [`RepeatAnimation::Forever`].
@@ -231,4 +373,170 @@ impl AnimationPlayer2D {
     pub fn replay(&mut self) {
         self.animation.replay();
     }
+
+    /// Current position in the animation as a fraction of its duration, clamped to `[0.0, 1.0]`.
+    ///
+    /// Returns `0.0` if `clips` doesn't (yet) contain the playing clip's asset.
+    pub fn progress(&self, clips: &Assets<AnimationClip2D>) -> f32 {
+        let Some(duration) = clips.get(self.animation_clip()).map(|clip| clip.duration()) else {
+            return 0.0;
+        };
+        if duration > 0.0 {
+            (self.animation.seek_time / duration).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Seek to a fraction of the animation's duration, clamped to `[0.0, 1.0]`.
+    ///
+    /// Does nothing if `clips` doesn't (yet) contain the playing clip's asset, so this can be
+    /// chained right after [`Self::start`]/[`Self::play`] without a one-frame delay.
+    pub fn set_progress(&mut self, progress: f32, clips: &Assets<AnimationClip2D>) -> &mut Self {
+        if let Some(duration) = clips.get(self.animation_clip()).map(|clip| clip.duration()) {
+            self.animation.seek_time = progress.clamp(0.0, 1.0) * duration;
+        }
+        self
+    }
+}
+
+/// Crossfades between the animation currently playing on a sibling [`AnimationPlayer2D`] and the
+/// animation it is transitioning away from, and chains a queued animation once the current one
+/// finishes.
+///
+/// Since sprite keyframes cannot be pixel-blended like skeletal animation, the fade is resolved by
+/// driving the dominant clip's sprite color alpha and swapping which clip drives the sprite index
+/// once the incoming animation's weight crosses `0.5`.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct AnimationTransitions2D {
+    /// The animation being faded out, if a transition is in progress.
+    outgoing: Option<PlayingAnimation2D>,
+    /// Weight of the incoming (current) animation, within `[0.0, 1.0]`. The outgoing animation's
+    /// weight is implicitly `1.0 - weight`.
+    weight: f32,
+    /// Rate at which `weight` approaches `1.0`, in weight-units per second.
+    weight_decline_per_sec: f32,
+    /// Animation to auto-start once the current animation on the sibling player finishes.
+    queued: Option<Handle<AnimationClip2D>>,
+}
+
+impl AnimationTransitions2D {
+    /// Start playing `handle` on `player`, fading out whatever it was previously playing over
+    /// `fade`.
+    pub fn play_with_transition(
+        &mut self,
+        player: &mut AnimationPlayer2D,
+        handle: Handle<AnimationClip2D>,
+        fade: Duration,
+    ) -> &mut Self {
+        let outgoing = std::mem::replace(
+            &mut player.animation,
+            PlayingAnimation2D {
+                animation_clip: handle,
+                ..Default::default()
+            },
+        );
+        self.outgoing = Some(outgoing);
+        self.weight = 0.0;
+        self.weight_decline_per_sec = if fade.as_secs_f32() > 0.0 {
+            1.0 / fade.as_secs_f32()
+        } else {
+            f32::INFINITY
+        };
+        self
+    }
+
+    /// Queue `handle` to automatically start once the sibling player's current animation reports
+    /// [`AnimationPlayer2D::is_finished`], without requiring callers to poll every frame.
+    pub fn queue(&mut self, handle: Handle<AnimationClip2D>) -> &mut Self {
+        self.queued = Some(handle);
+        self
+    }
+
+    /// Weight of the incoming (current) animation, within `[0.0, 1.0]`.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Whether a crossfade is currently in progress.
+    pub fn is_transitioning(&self) -> bool {
+        self.outgoing.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playing(direction: AnimationDirection, speed: f32) -> PlayingAnimation2D {
+        PlayingAnimation2D {
+            repeat: RepeatAnimation::Forever,
+            direction,
+            speed,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ping_pong_reflects_off_both_ends() {
+        let mut anim = playing(AnimationDirection::PingPong, 1.0);
+
+        anim.update(0.6, 1.0);
+        assert!((anim.seek_time - 0.6).abs() < 1e-6);
+
+        // Overshoots the `1.0` end by `0.2`; should reflect back to `0.8`.
+        anim.update(0.6, 1.0);
+        assert!((anim.seek_time - 0.8).abs() < 1e-6, "{}", anim.seek_time);
+        assert!(!anim.forward);
+    }
+
+    #[test]
+    fn ping_pong_converges_on_oversized_delta() {
+        let mut anim = playing(AnimationDirection::PingPong, 1.0);
+
+        // A delta many times the clip duration should still land in range rather than
+        // leaving `seek_time` outside `[0.0, clip_duration]`.
+        anim.update(37.5, 1.0);
+        assert!((0.0..=1.0).contains(&anim.seek_time));
+    }
+
+    #[test]
+    fn ping_pong_with_negative_speed_still_advances() {
+        // Negative `speed` reverses the travel direction implied by `forward`; this must not
+        // be confused with a bounce, or the animation freezes at its current bound instead of
+        // playing (the original bug this test guards against).
+        let mut anim = playing(AnimationDirection::PingPong, -1.0);
+        anim.seek_time = 0.5;
+
+        anim.update(0.1, 1.0);
+        assert!(
+            (anim.seek_time - 0.4).abs() < 1e-6,
+            "expected negative speed to move seek_time backwards, got {}",
+            anim.seek_time
+        );
+
+        // Overshoots the `0.0` bound by `0.1`; should reflect to `0.1` and then keep moving
+        // away from the bound rather than sticking to it.
+        anim.update(0.5, 1.0);
+        assert!((anim.seek_time - 0.1).abs() < 1e-6, "{}", anim.seek_time);
+        anim.update(0.1, 1.0);
+        assert!((anim.seek_time - 0.2).abs() < 1e-6, "{}", anim.seek_time);
+    }
+
+    #[test]
+    fn forwards_wraps_past_the_end() {
+        let mut anim = playing(AnimationDirection::Forwards, 1.0);
+
+        anim.update(1.5, 1.0);
+        assert!((anim.seek_time - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn zero_duration_clip_does_not_panic() {
+        let mut anim = playing(AnimationDirection::PingPong, 1.0);
+        let segments = anim.update(1.0, 0.0);
+        assert!(segments.is_empty());
+        assert_eq!(anim.seek_time, 0.0);
+    }
 }
@@ -0,0 +1,188 @@
+//! [`AssetLoader`](bevy::asset::AssetLoader) and manifest types for loading
+//! [`AnimationClip2D`](crate::asset::AnimationClip2D)/[`AnimationClipSet2D`](crate::asset::AnimationClipSet2D)
+//! assets from `.trickfilm` manifest files.
+//!
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, BoxedFuture, Handle, LoadContext},
+    prelude::Image,
+    sprite::TextureAtlas,
+    utils::HashMap,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{AnimationClip2D, AnimationClipSet2D, Easing, Keyframes2D};
+
+/// Manifest representation of an ordered list of keyframe indices inside a [`TextureAtlas`].
+#[derive(Debug, Deserialize)]
+pub enum IndicesManifest {
+    /// An explicit, ordered list of indices.
+    IndexVec(Vec<usize>),
+    /// A contiguous range of indices, `start..end`.
+    IndexRange(IndexRangeManifest),
+}
+
+/// A contiguous, inclusive-exclusive range of [`TextureAtlas`] indices.
+#[derive(Debug, Deserialize)]
+pub struct IndexRangeManifest {
+    /// First index of the range.
+    pub start: usize,
+    /// One past the last index of the range.
+    pub end: usize,
+}
+
+impl IndicesManifest {
+    fn into_indices(self) -> Vec<usize> {
+        match self {
+            IndicesManifest::IndexVec(indices) => indices,
+            IndicesManifest::IndexRange(range) => (range.start..range.end).collect(),
+        }
+    }
+}
+
+/// Manifest representation of [`Keyframes2D`].
+#[derive(Debug, Deserialize)]
+pub enum Keyframes2DManifest {
+    /// A [`TextureAtlas`] path and the ordered indices that make up the animation.
+    SpriteSheet(String, IndicesManifest),
+    /// An ordered list of [`Image`] paths, one per frame.
+    Sprite(Vec<String>),
+}
+
+/// Per-clip duration, either as a single total or as a per-frame value.
+///
+/// See [`AnimationClip2DManifest::duration`].
+#[derive(Debug, Deserialize)]
+pub enum DurationManifest {
+    /// Total duration of the clip in seconds, spread evenly across its frames.
+    Total(f32),
+    /// Duration of a single frame in seconds. The clip's total duration becomes
+    /// `frame_count * per_frame`.
+    PerFrame(f32),
+}
+
+/// Manifest representation of a single [`AnimationClip2D`].
+#[derive(Debug, Deserialize)]
+pub struct AnimationClip2DManifest {
+    /// Keyframe timestamps in seconds. If `None`, timestamps are evenly spread across `duration`.
+    pub keyframe_timestamps: Option<Vec<f32>>,
+    /// Keyframes that make up this animation.
+    pub keyframes: Keyframes2DManifest,
+    /// Duration of this animation clip.
+    pub duration: DurationManifest,
+    /// Easing curve applied to normalized playback progress before keyframe selection.
+    #[serde(default)]
+    pub easing: Easing,
+    /// Named markers attached to specific keyframe indices, e.g. `{ 3: "footstep", 6: "hit" }`.
+    #[serde(default)]
+    pub markers: HashMap<usize, String>,
+}
+
+/// Manifest representation of an [`AnimationClipSet2D`].
+#[derive(Debug, Deserialize)]
+pub struct AnimationClipSet2DManifest {
+    /// Optional name of this animation set.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A map of all animations in this set, identified by their names.
+    pub animations: HashMap<String, AnimationClip2DManifest>,
+}
+
+/// Possible errors that can be produced by [`Animation2DLoader`].
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum Animation2DLoaderError {
+    /// An [IO](std::io) error.
+    #[error("Could not load asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) error.
+    #[error("Could not parse RON: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+}
+
+fn clip_from_manifest(
+    manifest: AnimationClip2DManifest,
+    load_context: &mut LoadContext,
+) -> AnimationClip2D {
+    let (keyframes, frame_count) = match manifest.keyframes {
+        Keyframes2DManifest::SpriteSheet(path, indices) => {
+            let handle: Handle<TextureAtlas> = load_context.load(path);
+            let indices = indices.into_indices();
+            let frame_count = indices.len();
+            (Keyframes2D::SpriteSheet(handle, indices), frame_count)
+        }
+        Keyframes2DManifest::Sprite(paths) => {
+            let handles: Vec<Handle<Image>> =
+                paths.into_iter().map(|path| load_context.load(path)).collect();
+            let frame_count = handles.len();
+            (Keyframes2D::Sprite(handles), frame_count)
+        }
+    };
+
+    let (keyframe_timestamps, duration) = match manifest.duration {
+        DurationManifest::Total(total) => {
+            let timestamps = manifest.keyframe_timestamps.unwrap_or_else(|| {
+                (0..frame_count)
+                    .map(|i| total * i as f32 / frame_count as f32)
+                    .collect()
+            });
+            (timestamps, total)
+        }
+        DurationManifest::PerFrame(per_frame) => {
+            let timestamps = manifest
+                .keyframe_timestamps
+                .unwrap_or_else(|| (0..frame_count).map(|i| i as f32 * per_frame).collect());
+            (timestamps, frame_count as f32 * per_frame)
+        }
+    };
+
+    AnimationClip2D {
+        keyframe_timestamps,
+        keyframes,
+        duration,
+        easing: manifest.easing,
+        markers: manifest.markers,
+    }
+}
+
+/// Loads `.trickfilm` manifest files, yielding an [`AnimationClipSet2D`] asset
+/// (and its [`AnimationClip2D`]s as labeled sub-assets).
+#[derive(Default)]
+pub struct Animation2DLoader;
+
+impl AssetLoader for Animation2DLoader {
+    type Asset = AnimationClipSet2D;
+    type Settings = ();
+    type Error = Animation2DLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let manifest: AnimationClipSet2DManifest = ron::de::from_bytes(&bytes)?;
+
+            let mut animations = HashMap::default();
+            for (name, clip_manifest) in manifest.animations {
+                let clip = clip_from_manifest(clip_manifest, load_context);
+                let handle = load_context
+                    .add_labeled_asset(name.clone(), clip);
+                animations.insert(name, handle);
+            }
+
+            Ok(AnimationClipSet2D {
+                name: manifest.name,
+                animations,
+            })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["trickfilm"]
+    }
+}
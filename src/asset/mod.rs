@@ -47,6 +47,10 @@ pub struct AnimationClip2D {
     keyframes: Keyframes2D,
     /// Total duration of this animation clip in seconds.
     duration: f32,
+    /// Easing curve applied to normalized playback progress before keyframe selection.
+    easing: Easing,
+    /// Named markers attached to specific keyframe indices, used to emit [`AnimationEvent2D`](crate::animation::AnimationEvent2D)s.
+    markers: HashMap<usize, String>,
 }
 
 impl AnimationClip2D {
@@ -67,6 +71,86 @@ impl AnimationClip2D {
     pub fn duration(&self) -> f32 {
         self.duration
     }
+
+    /// Easing curve applied to normalized playback progress before keyframe selection.
+    #[inline]
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Named markers attached to specific keyframe indices.
+    #[inline]
+    pub fn markers(&self) -> &HashMap<usize, String> {
+        &self.markers
+    }
+}
+
+/// Easing curve that warps normalized playback progress (`seek_time / duration`) before keyframe
+/// selection, enabling anticipation/overshoot timing without re-authoring frame timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Deserialize)]
+pub enum Easing {
+    /// No remapping; progress advances linearly.
+    #[default]
+    Linear,
+    /// Quadratic ease-in: starts slow, accelerates towards the end.
+    EaseIn,
+    /// Quadratic ease-out: starts fast, decelerates towards the end.
+    EaseOut,
+    /// Quadratic ease-in-out: slow at both ends, fast through the middle.
+    EaseInOut,
+    /// No remapping of progress itself; provided so manifests can express intent to hold each
+    /// keyframe for its full bracket rather than blend towards the next.
+    Stepped,
+    /// A cubic Bezier easing curve defined by its two control points. The curve's endpoints are
+    /// fixed at `(0.0, 0.0)` and `(1.0, 1.0)`.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Remap normalized progress `t` (expected within `[0.0, 1.0]`) through this easing curve.
+    pub fn remap(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear | Easing::Stepped => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::CubicBezier(p1x, p1y, p2x, p2y) => cubic_bezier_y_for_x(t, p1x, p1y, p2x, p2y),
+        }
+    }
+}
+
+/// Evaluate a cubic Bezier curve's component at parameter `u`, with endpoints fixed at `0.0`/`1.0`.
+fn cubic_bezier_component(u: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - u;
+    3.0 * inv * inv * u * p1 + 3.0 * inv * u * u * p2 + u * u * u
+}
+
+/// Derivative of [`cubic_bezier_component`] with respect to `u`.
+fn cubic_bezier_component_derivative(u: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - u;
+    3.0 * inv * inv * p1 + 6.0 * inv * u * (p2 - p1) + 3.0 * u * u * (1.0 - p2)
+}
+
+/// Solve for the curve's Y output at a given X by running a few Newton iterations on the
+/// parametric X curve, clamping `u` to `[0.0, 1.0]` after each step.
+fn cubic_bezier_y_for_x(x: f32, p1x: f32, p1y: f32, p2x: f32, p2y: f32) -> f32 {
+    let mut u = x;
+    for _ in 0..6 {
+        let dx = cubic_bezier_component_derivative(u, p1x, p2x);
+        if dx.abs() < 1e-6 {
+            break;
+        }
+        u -= (cubic_bezier_component(u, p1x, p2x) - x) / dx;
+        u = u.clamp(0.0, 1.0);
+    }
+    cubic_bezier_component(u, p1y, p2y)
 }
 
 /// AnimationClipSet for 2D animations.
@@ -93,3 +177,46 @@ impl AnimationClipSet2D {
         &self.animations
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_and_stepped_pass_through_unchanged() {
+        assert_eq!(Easing::Linear.remap(0.25), 0.25);
+        assert_eq!(Easing::Stepped.remap(0.25), 0.25);
+    }
+
+    #[test]
+    fn ease_in_out_is_symmetric_around_the_midpoint() {
+        assert_eq!(Easing::EaseInOut.remap(0.0), 0.0);
+        assert_eq!(Easing::EaseInOut.remap(1.0), 1.0);
+        assert!((Easing::EaseInOut.remap(0.5) - 0.5).abs() < 1e-6);
+        assert!(Easing::EaseInOut.remap(0.25) < 0.25);
+        assert!(Easing::EaseInOut.remap(0.75) > 0.75);
+    }
+
+    #[test]
+    fn remap_clamps_out_of_range_progress() {
+        assert_eq!(Easing::EaseIn.remap(-1.0), 0.0);
+        assert_eq!(Easing::EaseIn.remap(2.0), 1.0);
+    }
+
+    #[test]
+    fn cubic_bezier_reaches_both_endpoints() {
+        let easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+        assert!((easing.remap(0.0) - 0.0).abs() < 1e-3);
+        assert!((easing.remap(1.0) - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn cubic_bezier_linear_control_points_approximate_identity() {
+        // Control points on the diagonal make the curve (approximately) `y == x`.
+        let easing = Easing::CubicBezier(0.0, 0.0, 1.0, 1.0);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!((easing.remap(t) - t).abs() < 1e-3, "t = {t}");
+        }
+    }
+}
@@ -18,7 +18,7 @@
 //!                     [0]
 //!                 ),
 //!             ),
-//!             duration: 0.1,                                      /* Complete duration of the animation. */
+//!             duration: Total(0.1),                               /* Complete duration of the animation. Use PerFrame(0.05) instead to give the duration of a single frame. */
 //!         ),
 //!         "run": (
 //!             keyframe_timestamps: None,                          /* Will automatically calculate the timestamps. */
@@ -28,7 +28,7 @@
 //!                     (start: 1, end: 7)
 //!                 ),
 //!             ),
-//!             duration: 0.6,
+//!             duration: Total(0.6),
 //!         ),
 //!    },
 //! )
@@ -107,7 +107,10 @@ impl Plugin for Animation2DPlugin {
 
 /// `use bevy_trickfilm::prelude::*;` to import common components and plugins.
 pub mod prelude {
-    pub use crate::animation::{AnimationPlayer2D, AnimationPlayer2DPlugin};
+    pub use crate::animation::{
+        AnimationDirection, AnimationEvent2D, AnimationFinished2D, AnimationPlayer2D,
+        AnimationPlayer2DPlugin, AnimationTransitions2D,
+    };
     pub use crate::asset::{Animation2DLoaderPlugin, AnimationClip2D, AnimationClipSet2D};
     pub use crate::Animation2DPlugin;
 }